@@ -1,29 +1,99 @@
+use pyo3::exceptions::{PyOverflowError, PyValueError};
 use pyo3::prelude::*;
-use hildie_lib::{greet, add};
+use hildie_lib::{
+    checked_add, greet_lang, greet_opt, greet_wrapped, greet_with, Greeter as LibGreeter, Lang,
+    DEFAULT_GREETING_TEMPLATE,
+};
 
-/// Python bindings for Hildie Rust library
+/// Author attributed in the module's `AUTHOR` metadata constant.
+const AUTHOR: &str = "Clinton Steiner";
+
+/// Native PyO3 bindings for the Hildie Rust greeting library.
 #[pymodule]
 fn hildie_bindings(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(py_greet, m)?)?;
     m.add_function(wrap_pyfunction!(py_add, m)?)?;
     m.add_function(wrap_pyfunction!(greet_all, m)?)?;
+    m.add_function(wrap_pyfunction!(greet_template, m)?)?;
+    m.add_function(wrap_pyfunction!(py_greet_wrapped, m)?)?;
+    m.add_function(wrap_pyfunction!(greet_all_wrapped, m)?)?;
+    m.add_function(wrap_pyfunction!(py_greet_lang, m)?)?;
+    m.add_class::<Greeter>()?;
+    m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    m.add("AUTHOR", AUTHOR)?;
+    m.add("DEFAULT_GREETING_TEMPLATE", DEFAULT_GREETING_TEMPLATE)?;
     Ok(())
 }
 
-/// Greet a person (Python binding)
+/// A reusable greeter that carries a configured prefix across many calls
+/// (e.g. `Greeter("Hi").greet("Ada")`).
+#[pyclass]
+struct Greeter {
+    inner: LibGreeter,
+}
+
+#[pymethods]
+impl Greeter {
+    #[new]
+    fn new(greeting: &str) -> Self {
+        Greeter {
+            inner: LibGreeter::new(greeting),
+        }
+    }
+
+    fn greet(&self, name: &str) -> PyResult<String> {
+        Ok(self.inner.greet(name))
+    }
+}
+
+/// Greet a person (Python binding). `name` is optional; a missing name
+/// produces the default `"Hello, world!"` greeting.
+#[pyfunction]
+#[pyo3(signature = (name=None))]
+fn py_greet(name: Option<String>) -> PyResult<String> {
+    Ok(greet_opt(name.as_deref()))
+}
+
+/// Greet using a caller-supplied `{name}` template (Python binding)
 #[pyfunction]
-fn py_greet(name: String) -> PyResult<String> {
-    Ok(greet(&name))
+#[pyo3(signature = (template, name=None))]
+fn greet_template(template: String, name: Option<String>) -> PyResult<String> {
+    Ok(greet_with(&template, name.as_deref()))
 }
 
-/// Add two numbers (Python binding)
+/// Add two numbers (Python binding). Raises `OverflowError` instead of
+/// wrapping when the sum doesn't fit in an `i32`.
 #[pyfunction]
 fn py_add(a: i32, b: i32) -> PyResult<i32> {
-    Ok(add(a, b))
+    checked_add(a, b).map_err(|e| PyOverflowError::new_err(e.to_string()))
 }
 
 /// Greet multiple people
 #[pyfunction]
 fn greet_all(names: Vec<String>) -> PyResult<Vec<String>> {
-    Ok(names.iter().map(|n| greet(n)).collect())
+    Ok(names.iter().map(|n| greet_opt(Some(n))).collect())
+}
+
+/// Greet a person and reflow the greeting to at most `width` columns
+/// (Python binding)
+#[pyfunction(name = "greet_wrapped")]
+fn py_greet_wrapped(name: String, width: usize) -> PyResult<String> {
+    Ok(greet_wrapped(&name, width))
+}
+
+/// Greet multiple people, reflowing each greeting to at most `width` columns
+#[pyfunction]
+fn greet_all_wrapped(names: Vec<String>, width: usize) -> PyResult<Vec<String>> {
+    Ok(names.iter().map(|n| greet_wrapped(n, width)).collect())
+}
+
+/// Greet a person in the language identified by a BCP-47-ish code (e.g.
+/// `"fr"` or `"es-ES"`), falling back to English for unknown locales
+/// (Python binding)
+#[pyfunction(name = "greet_lang")]
+fn py_greet_lang(name: String, lang_code: String) -> PyResult<String> {
+    if lang_code.is_empty() {
+        return Err(PyValueError::new_err("lang_code must not be empty"));
+    }
+    Ok(greet_lang(&name, Lang::from_code(&lang_code)))
 }
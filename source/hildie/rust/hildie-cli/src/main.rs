@@ -1,14 +1,41 @@
-use hildie_lib::greet;
+use hildie_lib::{greet_lang, greet_opt, wrap, Lang, DEFAULT_NAME};
 use std::env;
 
 fn main() {
     let args: Vec<String> = env::args().collect();
 
-    if args.len() < 2 {
-        eprintln!("Usage: {} <name>", args[0]);
-        std::process::exit(1);
+    let mut name: Option<&str> = None;
+    let mut width: Option<usize> = None;
+    let mut lang: Option<&str> = None;
+
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        if arg == "--width" {
+            let value = iter.next().unwrap_or_else(|| {
+                eprintln!("Usage: {} [name] [--width N] [--lang CODE]", args[0]);
+                std::process::exit(1);
+            });
+            width = Some(value.parse().unwrap_or_else(|_| {
+                eprintln!("Invalid width: {}", value);
+                std::process::exit(1);
+            }));
+        } else if arg == "--lang" {
+            lang = Some(iter.next().unwrap_or_else(|| {
+                eprintln!("Usage: {} [name] [--width N] [--lang CODE]", args[0]);
+                std::process::exit(1);
+            }));
+        } else {
+            name = Some(arg);
+        }
     }
 
-    let name = &args[1];
-    println!("{}", greet(name));
+    let greeting = match lang {
+        Some(code) => greet_lang(name.unwrap_or(DEFAULT_NAME), Lang::from_code(code)),
+        None => greet_opt(name),
+    };
+
+    match width {
+        Some(width) => println!("{}", wrap(&greeting, width)),
+        None => println!("{}", greeting),
+    }
 }
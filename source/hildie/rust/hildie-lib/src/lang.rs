@@ -0,0 +1,69 @@
+//! Language-selectable greeting templates.
+
+/// Supported greeting languages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lang {
+    En,
+    Fr,
+    Es,
+    De,
+}
+
+impl Lang {
+    /// Parse a BCP-47-ish language code (e.g. `"fr"` or `"es-ES"`) by taking
+    /// its primary subtag, falling back to [`Lang::En`] for unknown locales.
+    pub fn from_code(code: &str) -> Lang {
+        let primary = code.split('-').next().unwrap_or(code);
+        match primary.to_ascii_lowercase().as_str() {
+            "fr" => Lang::Fr,
+            "es" => Lang::Es,
+            "de" => Lang::De,
+            _ => Lang::En,
+        }
+    }
+
+    /// The greeting template for this language, with a `{name}` placeholder.
+    fn template(self) -> &'static str {
+        match self {
+            Lang::En => "Hello {name}!",
+            Lang::Fr => "Bonjour {name} !",
+            Lang::Es => "Hola {name}!",
+            Lang::De => "Hallo {name}!",
+        }
+    }
+}
+
+/// Greet `name` in the given language.
+pub fn greet_lang(name: &str, lang: Lang) -> String {
+    lang.template().replace("{name}", name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_greet_lang_french() {
+        assert_eq!(greet_lang("Ada", Lang::Fr), "Bonjour Ada !");
+    }
+
+    #[test]
+    fn test_greet_lang_spanish() {
+        assert_eq!(greet_lang("Ada", Lang::Es), "Hola Ada!");
+    }
+
+    #[test]
+    fn test_greet_lang_english_default() {
+        assert_eq!(greet_lang("Ada", Lang::En), "Hello Ada!");
+    }
+
+    #[test]
+    fn test_from_code_primary_subtag() {
+        assert_eq!(Lang::from_code("es-ES"), Lang::Es);
+    }
+
+    #[test]
+    fn test_from_code_unknown_falls_back_to_english() {
+        assert_eq!(Lang::from_code("zz"), Lang::En);
+    }
+}
@@ -1,8 +1,34 @@
 //! Hildie Rust Library
 
+mod lang;
+
+pub use lang::{greet_lang, Lang};
+
+/// Default template used when the caller doesn't supply their own.
+pub const DEFAULT_GREETING_TEMPLATE: &str = "Hello from Hildie Rust Library, {name}!";
+
+/// Default name substituted when no name is given.
+pub const DEFAULT_NAME: &str = "world";
+
 /// Greet returns a greeting message
 pub fn greet(name: &str) -> String {
-    format!("Hello from Hildie Rust Library, {}!", name)
+    greet_opt(Some(name))
+}
+
+/// Greet an optional name, falling back to a sensible default greeting
+/// (`"Hello, world!"`) when no name is supplied.
+pub fn greet_opt(name: Option<&str>) -> String {
+    match name {
+        Some(name) => greet_with(DEFAULT_GREETING_TEMPLATE, Some(name)),
+        None => "Hello, world!".to_string(),
+    }
+}
+
+/// Greet using a caller-supplied template containing a `{name}` placeholder,
+/// defaulting the name to `"world"` when `None` is passed.
+pub fn greet_with(template: &str, name: Option<&str>) -> String {
+    let name = name.unwrap_or(DEFAULT_NAME);
+    template.replace("{name}", name)
 }
 
 /// Add returns the sum of two integers
@@ -10,6 +36,96 @@ pub fn add(a: i32, b: i32) -> i32 {
     a + b
 }
 
+/// An arithmetic operation that would overflow `i32`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArithmeticError;
+
+impl std::fmt::Display for ArithmeticError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "arithmetic operation overflowed i32")
+    }
+}
+
+impl std::error::Error for ArithmeticError {}
+
+/// Add two integers, returning `Err(ArithmeticError)` on overflow instead of
+/// silently wrapping.
+pub fn checked_add(a: i32, b: i32) -> Result<i32, ArithmeticError> {
+    a.checked_add(b).ok_or(ArithmeticError)
+}
+
+/// Subtract two integers, returning `Err(ArithmeticError)` on overflow
+/// instead of silently wrapping.
+pub fn checked_sub(a: i32, b: i32) -> Result<i32, ArithmeticError> {
+    a.checked_sub(b).ok_or(ArithmeticError)
+}
+
+/// Multiply two integers, returning `Err(ArithmeticError)` on overflow
+/// instead of silently wrapping.
+pub fn checked_mul(a: i32, b: i32) -> Result<i32, ArithmeticError> {
+    a.checked_mul(b).ok_or(ArithmeticError)
+}
+
+/// A reusable greeter that carries a configured prefix across many calls.
+pub struct Greeter {
+    greeting: String,
+}
+
+impl Greeter {
+    /// Construct a `Greeter` with the given greeting prefix.
+    pub fn new(greeting: &str) -> Self {
+        Greeter {
+            greeting: greeting.to_string(),
+        }
+    }
+
+    /// Greet `name` using this greeter's configured prefix.
+    pub fn greet(&self, name: &str) -> String {
+        format!("{}, {}!", self.greeting, name)
+    }
+}
+
+/// Greet `name` and reflow the result to at most `width` columns, breaking
+/// on whitespace, never mid-word, and preserving existing newlines.
+pub fn greet_wrapped(name: &str, width: usize) -> String {
+    wrap(&greet(name), width)
+}
+
+/// Reflow `text` to at most `width` columns, breaking only on whitespace and
+/// preserving existing newlines as hard line breaks.
+pub fn wrap(text: &str, width: usize) -> String {
+    text.lines()
+        .map(|line| wrap_line(line, width))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn wrap_line(line: &str, width: usize) -> String {
+    if width == 0 {
+        return line.to_string();
+    }
+
+    let mut wrapped = String::new();
+    let mut current_width = 0;
+
+    for word in line.split_whitespace() {
+        if current_width == 0 {
+            wrapped.push_str(word);
+            current_width = word.len();
+        } else if current_width + 1 + word.len() <= width {
+            wrapped.push(' ');
+            wrapped.push_str(word);
+            current_width += 1 + word.len();
+        } else {
+            wrapped.push('\n');
+            wrapped.push_str(word);
+            current_width = word.len();
+        }
+    }
+
+    wrapped
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -22,8 +138,93 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_greet_opt_some() {
+        assert_eq!(
+            greet_opt(Some("World")),
+            "Hello from Hildie Rust Library, World!"
+        );
+    }
+
+    #[test]
+    fn test_greet_opt_none() {
+        assert_eq!(greet_opt(None), "Hello, world!");
+    }
+
+    #[test]
+    fn test_greet_with_custom_template() {
+        assert_eq!(
+            greet_with("Hey there, {name}!", Some("Ada")),
+            "Hey there, Ada!"
+        );
+    }
+
+    #[test]
+    fn test_greet_with_default_name() {
+        assert_eq!(greet_with("Hey there, {name}!", None), "Hey there, world!");
+    }
+
     #[test]
     fn test_add() {
         assert_eq!(add(2, 3), 5);
     }
+
+    #[test]
+    fn test_checked_add_ok() {
+        assert_eq!(checked_add(2, 3), Ok(5));
+    }
+
+    #[test]
+    fn test_checked_add_overflow() {
+        assert_eq!(checked_add(i32::MAX, 1), Err(ArithmeticError));
+    }
+
+    #[test]
+    fn test_checked_sub_overflow() {
+        assert_eq!(checked_sub(i32::MIN, 1), Err(ArithmeticError));
+    }
+
+    #[test]
+    fn test_checked_mul_overflow() {
+        assert_eq!(checked_mul(i32::MAX, 2), Err(ArithmeticError));
+    }
+
+    #[test]
+    fn test_greeter_greet() {
+        let greeter = Greeter::new("Hi");
+        assert_eq!(greeter.greet("Ada"), "Hi, Ada!");
+    }
+
+    #[test]
+    fn test_greeter_reused_across_calls() {
+        let greeter = Greeter::new("Yo");
+        assert_eq!(greeter.greet("Alice"), "Yo, Alice!");
+        assert_eq!(greeter.greet("Bob"), "Yo, Bob!");
+    }
+
+    #[test]
+    fn test_greet_wrapped_breaks_on_whitespace() {
+        assert_eq!(
+            greet_wrapped("World", 20),
+            "Hello from Hildie\nRust Library, World!"
+        );
+    }
+
+    #[test]
+    fn test_greet_wrapped_never_breaks_mid_word() {
+        let wrapped = greet_wrapped("World", 5);
+        for line in wrapped.lines() {
+            assert!(line.split_whitespace().count() <= 1);
+        }
+        let original = greet("World");
+        assert_eq!(
+            wrapped.split_whitespace().collect::<Vec<_>>(),
+            original.split_whitespace().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_wrap_preserves_existing_newlines() {
+        assert_eq!(wrap("one two\nthree four", 100), "one two\nthree four");
+    }
 }